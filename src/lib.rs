@@ -83,6 +83,478 @@ pub fn accurate_snooze(start: Instant, total: Duration, len: Duration) -> bool {
     }
 }
 
+/// Sleeps for `total` in steps of up to `len` and returns control flow inbetween, yielding to the
+/// async runtime between steps instead of parking the OS thread.
+///
+/// The async counterpart of [snooze](snooze), for use inside tokio/async-std tasks: an existing
+/// `while isleep::snooze(...)` loop translates directly to `while async_snooze(...).await`.
+///
+/// The underlying timer is selected by the enabled runtime feature: `tokio` uses
+/// [tokio::time::sleep] and `async-std` uses `async_std::task::sleep` (`tokio` takes precedence if
+/// both are enabled). Enable one of them alongside `async`, e.g.
+/// `cargo add isleep --features=async,tokio`.
+///
+/// Each step is clamped to a minimum of 1 ms so a zero or tiny `len` still makes forward progress
+/// instead of busy-yielding. Accuracy here is bounded by the async runtime's timer wheel rather
+/// than the OS sleep.
+///
+/// # Examples
+/// ```no_run
+/// # #[cfg(all(feature = "async", any(feature = "tokio", feature = "async-std")))] {
+/// # async fn demo() {
+/// use isleep::async_snooze;
+///
+/// let total = std::time::Duration::from_secs(1);
+/// let len = std::time::Duration::from_millis(100);
+/// let start = std::time::Instant::now();
+/// while async_snooze(start, total, len).await {
+///     println!("Checking if the user pressed CTRL+C...");
+/// }
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn async_snooze(start: Instant, total: Duration, len: Duration) -> bool {
+    match total.checked_sub(start.elapsed()) {
+        None => false,
+        Some(dt) => {
+            let step = len.min(dt).max(Duration::from_millis(1));
+            async_sleep(step).await;
+            true
+        }
+    }
+}
+
+/// Yields to the enabled async runtime for `dur`, backing [async_snooze](async_snooze).
+#[cfg(all(feature = "async", feature = "tokio"))]
+async fn async_sleep(dur: Duration) {
+    tokio::time::sleep(dur).await;
+}
+
+/// Yields to the enabled async runtime for `dur`, backing [async_snooze](async_snooze).
+#[cfg(all(feature = "async", feature = "async-std", not(feature = "tokio")))]
+async fn async_sleep(dur: Duration) {
+    async_std::task::sleep(dur).await;
+}
+
+/// The error returned by [parse_duration](parse_duration) when a string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The input was empty or contained only a unit suffix.
+    Empty,
+    /// The numeric part of the input could not be parsed as a number.
+    InvalidNumber(String),
+    /// The input described a negative duration.
+    Negative(String),
+}
+
+impl std::fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDurationError::Empty => write!(f, "empty duration"),
+            ParseDurationError::InvalidNumber(s) => write!(f, "invalid duration number: {s:?}"),
+            ParseDurationError::Negative(s) => write!(f, "negative durations are not allowed: {s:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses a human-friendly duration string into a [Duration].
+///
+/// Accepts the suffixes GNU-style `sleep` understands: none or `s` for seconds, `m` for minutes,
+/// `h` for hours, plus `ms` for milliseconds. A bare number is treated as seconds. Fractional
+/// values such as `"1.5s"` or `"0.0001h"` are allowed; negatives and garbage are rejected with a
+/// descriptive [ParseDurationError].
+///
+/// This lets callers feed parsed values straight into
+/// [snooze](snooze)`(start, parse_duration(total)?, parse_duration(len)?)`.
+///
+/// # Examples
+/// ```
+/// use isleep::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("0.1").unwrap(), Duration::from_millis(100));
+/// assert_eq!(parse_duration("100ms").unwrap(), Duration::from_millis(100));
+/// assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+/// assert!(parse_duration("xyz").is_err());
+/// assert!(parse_duration("-1").is_err());
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    // Order matters: `ms` must be matched before the single-character `m`/`s`.
+    let (number, scale) = if let Some(n) = s.strip_suffix("ms") {
+        (n, 1e-3)
+    } else if let Some(n) = s.strip_suffix('s') {
+        (n, 1.0)
+    } else if let Some(n) = s.strip_suffix('m') {
+        (n, 60.0)
+    } else if let Some(n) = s.strip_suffix('h') {
+        (n, 3600.0)
+    } else {
+        (s, 1.0)
+    };
+
+    let number = number.trim();
+    if number.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    let value: f64 = number
+        .parse()
+        .map_err(|_| ParseDurationError::InvalidNumber(s.to_string()))?;
+    if !value.is_finite() {
+        return Err(ParseDurationError::InvalidNumber(s.to_string()));
+    }
+    if value < 0.0 {
+        return Err(ParseDurationError::Negative(s.to_string()));
+    }
+
+    Ok(Duration::from_secs_f64(value * scale))
+}
+
+/// A monotonic instant that keeps counting while the system is suspended.
+///
+/// [std::time::Instant] does not advance while the machine is suspended on Linux/Android, so a long
+/// [snooze](snooze) can drastically overshoot wall-clock after a laptop wakes from sleep. A
+/// `BootInstant` is backed by `CLOCK_BOOTTIME` on Linux/Android and `CLOCK_MONOTONIC_RAW` on
+/// macOS/iOS (the continuous clock equivalent to `mach_continuous_time`, which keeps counting while
+/// the system is asleep — unlike `CLOCK_MONOTONIC`/`CLOCK_UPTIME_RAW`, which pause), and falls back
+/// to [Instant] elsewhere.
+///
+/// Use it with [snooze_boot](snooze_boot) so that a caller waiting "one hour total" still
+/// terminates promptly after a mid-sleep suspend/resume instead of snoozing for the suspended
+/// duration as well.
+#[cfg(feature = "boottime")]
+#[derive(Debug, Clone, Copy)]
+pub struct BootInstant(BootInstantInner);
+
+#[cfg(all(
+    feature = "boottime",
+    any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")
+))]
+type BootInstantInner = Duration;
+
+#[cfg(all(
+    feature = "boottime",
+    not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios"))
+))]
+type BootInstantInner = Instant;
+
+#[cfg(all(feature = "boottime", any(target_os = "linux", target_os = "android")))]
+const BOOT_CLOCK: libc::clockid_t = libc::CLOCK_BOOTTIME;
+
+#[cfg(all(feature = "boottime", any(target_os = "macos", target_os = "ios")))]
+const BOOT_CLOCK: libc::clockid_t = libc::CLOCK_MONOTONIC_RAW;
+
+#[cfg(feature = "boottime")]
+impl BootInstant {
+    /// Returns an instant corresponding to "now", counting any time the system spends suspended.
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios"))]
+    pub fn now() -> Self {
+        // SAFETY: `ts` is a valid, fully initialized pointer and `BOOT_CLOCK` is a supported clock.
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        let ret = unsafe { libc::clock_gettime(BOOT_CLOCK, &mut ts) };
+        assert_eq!(ret, 0, "clock_gettime failed");
+        BootInstant(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+    }
+
+    /// Returns an instant corresponding to "now".
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+    pub fn now() -> Self {
+        BootInstant(Instant::now())
+    }
+
+    /// Returns the amount of time elapsed since this instant, including time spent suspended.
+    #[cfg(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios"))]
+    pub fn elapsed(&self) -> Duration {
+        Self::now().0.saturating_sub(self.0)
+    }
+
+    /// Returns the amount of time elapsed since this instant.
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", target_os = "ios")))]
+    pub fn elapsed(&self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+/// Sleeps for `total` in steps of up to `len` and returns control flow inbetween, measuring
+/// progress against a [BootInstant] so elapsed time includes any system suspend.
+///
+/// The suspend-aware counterpart of [snooze](snooze). For higher accuracy use the `accuracy`
+/// feature and [accurate_snooze_boot](accurate_snooze_boot).
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "boottime")] {
+/// use isleep::{snooze_boot, BootInstant};
+///
+/// let total = std::time::Duration::from_secs(1);
+/// let len = std::time::Duration::from_millis(100);
+/// let start = BootInstant::now();
+/// while snooze_boot(start, total, len) {
+///     println!("Checking if the user pressed CTRL+C...");
+/// }
+/// # }
+/// ```
+#[cfg(feature = "boottime")]
+pub fn snooze_boot(start: BootInstant, total: Duration, len: Duration) -> bool {
+    match total.checked_sub(start.elapsed()) {
+        None => false,
+        Some(dt) => {
+            std::thread::sleep(len.min(dt));
+            true
+        }
+    }
+}
+
+/// Sleeps for `total` in accurate steps of up to `len` and returns control flow inbetween,
+/// measuring progress against a [BootInstant] so elapsed time includes any system suspend.
+///
+/// The suspend-aware counterpart of [accurate_snooze](accurate_snooze).
+#[cfg(all(feature = "boottime", feature = "accuracy"))]
+pub fn accurate_snooze_boot(start: BootInstant, total: Duration, len: Duration) -> bool {
+    match total.checked_sub(start.elapsed()) {
+        None => false,
+        Some(dt) => {
+            spin_sleep::sleep(len.min(dt));
+            true
+        }
+    }
+}
+
+/// Selects what a [Snoozer] does while spinning down the last, untrusted part of a sleep.
+///
+/// Mirrors the strategies offered by [spin_sleep] so callers can trade CPU usage for a
+/// slightly tighter spin without depending on that crate directly.
+#[cfg(feature = "accuracy")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpinStrategy {
+    /// Calls [std::thread::yield_now] in the spin loop, giving other threads a chance to run.
+    YieldThread,
+    /// Calls [std::hint::spin_loop] in the spin loop for the tightest, busiest spin.
+    SpinLoopHint,
+}
+
+#[cfg(feature = "accuracy")]
+impl From<SpinStrategy> for spin_sleep::SpinStrategy {
+    fn from(strategy: SpinStrategy) -> Self {
+        match strategy {
+            SpinStrategy::YieldThread => spin_sleep::SpinStrategy::YieldThread,
+            SpinStrategy::SpinLoopHint => spin_sleep::SpinStrategy::SpinLoopHint,
+        }
+    }
+}
+
+/// A reusable, accurately configured snoozer.
+///
+/// Wraps a tuned [spin_sleep::SpinSleeper] so the native accuracy and spin behaviour can be chosen
+/// at runtime instead of per-crate-build, and so one configured sleeper can be shared across many
+/// snooze loops. This is the runtime-configurable counterpart of [accurate_snooze](accurate_snooze).
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "accuracy")] {
+/// use isleep::{Snoozer, SpinStrategy};
+///
+/// // Trust `thread::sleep` for all but the last 100 µs, then spin by yielding.
+/// let snoozer = Snoozer::new(100_000).with_spin_strategy(SpinStrategy::YieldThread);
+/// let total = std::time::Duration::from_secs(1);
+/// let len = std::time::Duration::from_millis(100);
+/// let start = std::time::Instant::now();
+/// while snoozer.snooze(start, total, len) {
+///     println!("Checking if the user pressed CTRL+C...");
+/// }
+/// # }
+/// ```
+#[cfg(feature = "accuracy")]
+#[derive(Debug, Clone)]
+pub struct Snoozer {
+    sleeper: spin_sleep::SpinSleeper,
+}
+
+#[cfg(feature = "accuracy")]
+impl Snoozer {
+    /// Creates a new snoozer that leaves all but the last `native_accuracy_ns` nanoseconds of each
+    /// step to [std::thread::sleep] and spins that last part.
+    pub fn new(native_accuracy_ns: u64) -> Self {
+        Self {
+            sleeper: spin_sleep::SpinSleeper::new(native_accuracy_ns.min(u32::MAX as u64) as u32),
+        }
+    }
+
+    /// Selects the [SpinStrategy] used while spinning.
+    pub fn with_spin_strategy(mut self, strategy: SpinStrategy) -> Self {
+        self.sleeper = self.sleeper.with_spin_strategy(strategy.into());
+        self
+    }
+
+    /// Sleeps for `total` in accurate steps of up to `len` and returns control flow inbetween.
+    ///
+    /// Behaves like [accurate_snooze](accurate_snooze) but with the accuracy and spin behaviour
+    /// configured on `self`.
+    pub fn snooze(&self, start: Instant, total: Duration, len: Duration) -> bool {
+        match total.checked_sub(start.elapsed()) {
+            None => false,
+            Some(dt) => {
+                self.sleeper.sleep(len.min(dt));
+                true
+            }
+        }
+    }
+}
+
+/// Builder for a [RateLimiter].
+///
+/// Created with [`RateLimiter::builder`]. Defaults match a general-purpose control loop: the
+/// [Snoozer] uses a native accuracy of 100 µs with [SpinStrategy::YieldThread], the report
+/// interval is one second, and no overall total is set (the loop runs until the caller stops).
+#[cfg(feature = "accuracy")]
+#[derive(Debug, Clone)]
+pub struct RateLimiterBuilder {
+    native_accuracy_ns: u64,
+    spin_strategy: SpinStrategy,
+    report_interval: Duration,
+    total: Option<Duration>,
+}
+
+#[cfg(feature = "accuracy")]
+impl Default for RateLimiterBuilder {
+    fn default() -> Self {
+        Self {
+            native_accuracy_ns: 100_000,
+            spin_strategy: SpinStrategy::YieldThread,
+            report_interval: Duration::from_secs(1),
+            total: None,
+        }
+    }
+}
+
+#[cfg(feature = "accuracy")]
+impl RateLimiterBuilder {
+    /// Sets the native accuracy in nanoseconds: the last part of each sleep that is spun instead of
+    /// left to [std::thread::sleep].
+    pub fn native_accuracy_ns(mut self, native_accuracy_ns: u64) -> Self {
+        self.native_accuracy_ns = native_accuracy_ns;
+        self
+    }
+
+    /// Selects the [SpinStrategy] used while spinning.
+    pub fn spin_strategy(mut self, spin_strategy: SpinStrategy) -> Self {
+        self.spin_strategy = spin_strategy;
+        self
+    }
+
+    /// Sets how often [`RateLimiter::report_rate`] returns a measured rate.
+    pub fn report_interval(mut self, report_interval: Duration) -> Self {
+        self.report_interval = report_interval;
+        self
+    }
+
+    /// Bounds the loop to run for at most `total`, after which [`RateLimiter::loop_sleep`] returns
+    /// `false`.
+    pub fn total(mut self, total: Duration) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// Builds a [RateLimiter] targeting `target_rate` iterations per second.
+    pub fn build_with_target_rate(self, target_rate: f64) -> RateLimiter {
+        let now = Instant::now();
+        RateLimiter {
+            snoozer: Snoozer::new(self.native_accuracy_ns).with_spin_strategy(self.spin_strategy),
+            target_interval: Duration::from_secs_f64(1.0 / target_rate),
+            total: self.total,
+            start: now,
+            last_start: now,
+            report_interval: self.report_interval,
+            last_report: now,
+            count: 0,
+        }
+    }
+}
+
+/// A fixed-rate control loop built on top of the accurate snooze logic.
+///
+/// Drives a loop at a target frequency (game ticks, sensor polling, UI refresh) while still
+/// letting the caller check for interrupts between iterations, and optionally reports the measured
+/// rate. This saves hand-rolling the arithmetic around [accurate_snooze](accurate_snooze).
+///
+/// # Examples
+/// ```no_run
+/// # #[cfg(feature = "accuracy")] {
+/// use isleep::RateLimiter;
+///
+/// let mut limiter = RateLimiter::builder().build_with_target_rate(60.0);
+/// while limiter.loop_sleep() {
+///     // ... do one tick of work, checking for interrupts ...
+///     if let Some(rate) = limiter.report_rate() {
+///         println!("running at {rate:.1} Hz");
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "accuracy")]
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    snoozer: Snoozer,
+    target_interval: Duration,
+    total: Option<Duration>,
+    start: Instant,
+    last_start: Instant,
+    report_interval: Duration,
+    last_report: Instant,
+    count: u64,
+}
+
+#[cfg(feature = "accuracy")]
+impl RateLimiter {
+    /// Starts building a rate limiter with the defaults described on [RateLimiterBuilder].
+    pub fn builder() -> RateLimiterBuilder {
+        RateLimiterBuilder::default()
+    }
+
+    /// Sleeps off the remainder of the current iteration's target interval, records the start of
+    /// the next iteration and returns whether the loop should continue.
+    ///
+    /// The returned value is `true` unless a `total` was configured and has now elapsed.
+    pub fn loop_sleep(&mut self) -> bool {
+        if let Some(dt) = self.target_interval.checked_sub(self.last_start.elapsed()) {
+            self.snoozer.sleeper.sleep(dt);
+        }
+        self.last_start = Instant::now();
+        self.count += 1;
+        match self.total {
+            Some(total) => self.start.elapsed() < total,
+            None => true,
+        }
+    }
+
+    /// Returns the measured iterations-per-second once per configured report interval.
+    ///
+    /// Computed from a rolling count of loops completed since the previous report; returns `None`
+    /// until the report interval has passed again.
+    pub fn report_rate(&mut self) -> Option<f64> {
+        let since = self.last_report.elapsed();
+        if since >= self.report_interval {
+            let rate = self.count as f64 / since.as_secs_f64();
+            self.count = 0;
+            self.last_report = Instant::now();
+            Some(rate)
+        } else {
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::snooze;
@@ -104,6 +576,77 @@ mod test {
         assert!(counter >= 0);
     }
 
+    #[cfg(feature = "accuracy")]
+    #[test]
+    fn test_snoozer() {
+        use super::{Snoozer, SpinStrategy};
+        let snoozer = Snoozer::new(100_000).with_spin_strategy(SpinStrategy::YieldThread);
+        let total = std::time::Duration::from_secs(1);
+        let len = std::time::Duration::from_millis(100);
+        let start = std::time::Instant::now();
+        let mut counter = 0;
+        while snoozer.snooze(start, total, len) {
+            counter += 1;
+        }
+        assert!(counter > 0);
+    }
+
+    #[cfg(feature = "accuracy")]
+    #[test]
+    fn test_rate_limiter() {
+        use super::RateLimiter;
+        let mut limiter = RateLimiter::builder()
+            .total(std::time::Duration::from_millis(100))
+            .build_with_target_rate(60.0);
+        let mut counter = 0;
+        while limiter.loop_sleep() {
+            counter += 1;
+        }
+        assert!(counter > 0);
+    }
+
+    #[cfg(feature = "boottime")]
+    #[test]
+    fn test_snooze_boot() {
+        use super::{snooze_boot, BootInstant};
+        let total = std::time::Duration::from_secs(1);
+        let len = std::time::Duration::from_millis(100);
+        let start = BootInstant::now();
+        let mut counter = 0;
+        while snooze_boot(start, total, len) {
+            counter += 1;
+        }
+        assert!(counter > 0);
+    }
+
+    #[cfg(all(feature = "async", feature = "tokio"))]
+    #[tokio::test]
+    async fn test_async_snooze() {
+        use super::async_snooze;
+        let total = std::time::Duration::from_secs(1);
+        let len = std::time::Duration::from_millis(100);
+        let start = std::time::Instant::now();
+        let mut counter = 0;
+        while async_snooze(start, total, len).await {
+            counter += 1;
+        }
+        assert!(counter > 0);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        use super::parse_duration;
+        use std::time::Duration;
+        assert_eq!(parse_duration("0.1").unwrap(), Duration::from_millis(100));
+        assert_eq!(parse_duration("100ms").unwrap(), Duration::from_millis(100));
+        assert_eq!(parse_duration("1.5s").unwrap(), Duration::from_millis(1500));
+        assert_eq!(parse_duration("0.01m").unwrap(), Duration::from_millis(600));
+        assert_eq!(parse_duration("0.0001h").unwrap(), Duration::from_millis(360));
+        assert!(parse_duration("xyz").is_err());
+        assert!(parse_duration("-1").is_err());
+        assert!(parse_duration("").is_err());
+    }
+
     #[test]
     fn test_readme_example() {
         // Sleeping for a total of 1 s